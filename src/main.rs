@@ -17,6 +17,9 @@ pub type ExecutorPtr = Arc<smol::Executor<'static>>;
 
 pub type AppPtr = Arc<App>;
 
+/// A boxed, `Send` unit of render-graph work driven by the frame executor.
+type BoxedJob = std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send>>;
+
 pub struct App {
     pub render_api: RenderApiPtr,
     pub ex: ExecutorPtr,
@@ -27,7 +30,7 @@ pub struct App {
 
     mesh1: SyncMutex<Option<MeshInfo>>,
     mesh2: SyncMutex<Option<MeshInfo>>,
-    mesh3: SyncMutex<MeshInfo>,
+    mesh3: SyncMutex<Option<MeshInfo>>,
 }
 
 impl App {
@@ -40,8 +43,8 @@ impl App {
             }
         });
 
-        let mesh3 = Self::regen_mesh3(&render_api);
-
+        // The meshes are created asynchronously from `start()` once the UI
+        // backend is up and able to acknowledge the buffer creation.
         Arc::new(Self {
             ex,
             render_api,
@@ -50,7 +53,7 @@ impl App {
             tasks: SyncMutex::new(vec![]),
             mesh1: SyncMutex::new(None),
             mesh2: SyncMutex::new(None),
-            mesh3: SyncMutex::new(mesh3),
+            mesh3: SyncMutex::new(None),
         })
     }
 
@@ -80,44 +83,127 @@ impl App {
         });
         self.tasks.lock().unwrap().push(resize_task);
 
-        std::thread::sleep(std::time::Duration::from_millis(2000));
-        debug!(target: "app", "Sleeping 2000 ms...");
+        // Create the stable mesh buffers, waiting for the backend to
+        // acknowledge each creation before the first draw.
+        if let Err(e) = self.clone().init_meshes().await {
+            error!(target: "app", "Failed to create initial meshes: {e}");
+            return
+        }
+
+        // Deterministically wait for the backend to have drained the initial
+        // mesh submissions, replacing the old hardcoded 2000 ms sleep.
+        self.render_api.submit_fence().wait().await;
 
         self.draw().await;
     }
 
-    pub async fn draw(&self) {
-        debug!(target: "ui::win", "Window::draw()");
+    async fn init_meshes(self: Arc<Self>) -> Result<(), RenderError> {
+        let mesh1 = Self::regen_mesh1(&self.render_api).await?;
+        let mesh2 = Self::regen_mesh2(&self.render_api).await?;
+        let mesh3 = Self::regen_mesh3(&self.render_api).await?;
+        *self.mesh1.lock().unwrap() = Some(mesh1);
+        *self.mesh2.lock().unwrap() = Some(mesh2);
+        *self.mesh3.lock().unwrap() = Some(mesh3);
+        Ok(())
+    }
 
-        let mut freed_buffers = vec![];
+    pub async fn draw(self: Arc<Self>) {
+        debug!(target: "ui::win", "Window::draw()");
 
-        let mesh3 = Self::regen_mesh3(&self.render_api);
-        let old_mesh = std::mem::replace(&mut *self.mesh3.lock().unwrap(), mesh3.clone());
-        freed_buffers.push(old_mesh.vertex_buffer);
-        freed_buffers.push(old_mesh.index_buffer);
+        // Express the frame as a render graph: each mesh upload is a node that
+        // writes its own buffers, and a final draw pass reads them all. The
+        // three uploads are independent so the scheduler places them in one
+        // level and we run them concurrently, replacing the old hand-rolled
+        // mesh3 -> mesh2 -> mesh1 sequencing.
+        let mut graph = RenderGraph::new();
+        let mut jobs: HashMap<NodeId, BoxedJob> = HashMap::new();
+        let mut drawn_buffers = Vec::new();
+
+        // Snapshot the mesh handles before awaiting so we never hold a lock
+        // across an `.await` point.
+        let mesh3 = self.mesh3.lock().unwrap().clone();
+        if let Some(mesh3) = mesh3 {
+            let mut writes = vec![mesh3.vertex_buffer, mesh3.index_buffer];
+            if let Some(instance_buffer) = mesh3.instance_buffer {
+                writes.push(instance_buffer);
+            }
+            drawn_buffers.extend_from_slice(&writes);
+            let node = graph.add_node("upload mesh3", vec![], writes);
+            let api = self.render_api.clone();
+            jobs.insert(
+                node,
+                Box::pin(async move {
+                    let (verts, indices, instances) = Self::mesh3_data();
+                    let _ = api.update_vertex_buffer(mesh3.vertex_buffer, verts).await;
+                    let _ = api.update_index_buffer(mesh3.index_buffer, indices).await;
+                    if let Some(instance_buffer) = mesh3.instance_buffer {
+                        let _ = api.update_instance_buffer(instance_buffer, instances).await;
+                    }
+                }),
+            );
+        }
 
-        let mesh2 = self.regen_mesh2();
-        let old_mesh = std::mem::replace(&mut *self.mesh2.lock().unwrap(), Some(mesh2.clone()));
-        if let Some(old) = old_mesh {
-            freed_buffers.push(old.vertex_buffer);
-            freed_buffers.push(old.index_buffer);
+        let mesh2 = self.mesh2.lock().unwrap().clone();
+        if let Some(mesh2) = mesh2 {
+            let writes = vec![mesh2.vertex_buffer, mesh2.index_buffer];
+            drawn_buffers.extend_from_slice(&writes);
+            let node = graph.add_node("upload mesh2", vec![], writes);
+            let api = self.render_api.clone();
+            jobs.insert(
+                node,
+                Box::pin(async move {
+                    let (verts, indices) = Self::mesh2_data();
+                    let _ = api.update_vertex_buffer(mesh2.vertex_buffer, verts).await;
+                    let _ = api.update_index_buffer(mesh2.index_buffer, indices).await;
+                }),
+            );
         }
 
-        let mesh1 = self.regen_mesh1();
-        let old_mesh = std::mem::replace(&mut *self.mesh1.lock().unwrap(), Some(mesh1.clone()));
-        if let Some(old) = old_mesh {
-            freed_buffers.push(old.vertex_buffer);
-            freed_buffers.push(old.index_buffer);
+        let mesh1 = self.mesh1.lock().unwrap().clone();
+        if let Some(mesh1) = mesh1 {
+            let writes = vec![mesh1.vertex_buffer, mesh1.index_buffer];
+            drawn_buffers.extend_from_slice(&writes);
+            let node = graph.add_node("upload mesh1", vec![], writes);
+            let api = self.render_api.clone();
+            jobs.insert(
+                node,
+                Box::pin(async move {
+                    let (verts, indices) = Self::mesh1_data();
+                    let _ = api.update_vertex_buffer(mesh1.vertex_buffer, verts).await;
+                    let _ = api.update_index_buffer(mesh1.index_buffer, indices).await;
+                }),
+            );
         }
 
-        for buff in freed_buffers {
-            self.render_api.delete_buffer(buff);
+        // The draw pass reads every uploaded buffer, so it is ordered after all
+        // of the uploads complete.
+        let draw_pass = graph.add_node("draw pass", drawn_buffers, vec![]);
+        jobs.insert(draw_pass, Box::pin(async {}));
+
+        let levels = match graph.schedule() {
+            Ok(levels) => levels,
+            Err(e) => {
+                error!(target: "ui::win", "Render graph scheduling failed: {e}");
+                return
+            }
+        };
+
+        for level in levels {
+            let mut tasks = Vec::with_capacity(level.len());
+            for node in level {
+                if let Some(job) = jobs.remove(&node) {
+                    tasks.push(self.ex.spawn(job));
+                }
+            }
+            for task in tasks {
+                task.await;
+            }
         }
 
         debug!(target: "ui::win", "Window::draw() - replaced draw call");
     }
 
-    fn regen_mesh1(&self) -> MeshInfo {
+    fn mesh1_data() -> (Vec<Vertex>, Vec<u16>) {
         let verts = vec![
             Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
             Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
@@ -125,15 +211,10 @@ impl App {
             Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
         ];
         let indices = vec![0, 2, 1, 1, 2, 3];
-
-        let num_elements = indices.len() as i32;
-        let vertex_buffer = self.render_api.new_vertex_buffer(verts);
-        let index_buffer = self.render_api.new_index_buffer(indices);
-
-        MeshInfo { vertex_buffer, index_buffer, num_elements }
+        (verts, indices)
     }
 
-    fn regen_mesh2(&self) -> MeshInfo {
+    fn mesh2_data() -> (Vec<Vertex>, Vec<u16>) {
         let verts = vec![
             Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
             Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
@@ -145,63 +226,106 @@ impl App {
             Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
         ];
         let indices = vec![0, 2, 1, 1, 2, 3, 4, 6, 5, 5, 6, 7];
-
-        let num_elements = indices.len() as i32;
-        let vertex_buffer = self.render_api.new_vertex_buffer(verts);
-        let index_buffer = self.render_api.new_index_buffer(indices);
-
-        MeshInfo { vertex_buffer, index_buffer, num_elements }
+        (verts, indices)
     }
 
-    fn regen_mesh3(render_api: &RenderApi) -> MeshInfo {
+    // A single base quad; the six repetitions are expressed as instances
+    // rather than six copies of the same four vertices.
+    fn mesh3_data() -> (Vec<Vertex>, Vec<u16>, Vec<InstanceData>) {
         let verts = vec![
             Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
             Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
             Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
             Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
-            Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
-            Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
-            Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
-            Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
-            Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
-            Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
-            Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
-            Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
-            Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
-            Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
-            Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
-            Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
-            Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
-            Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
-            Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
-            Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
-            Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
-            Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
-            Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
-            Vertex { pos: [0.0, 0.0], color: [0.0, 0.0, 0.0, 0.0], uv: [0.0, 0.0] },
         ];
-        let indices = vec![
-            0, 2, 1, 1, 2, 3, 4, 6, 5, 5, 6, 7, 8, 10, 9, 9, 10, 11, 12, 14, 13, 13, 14, 15, 16,
-            18, 17, 17, 18, 19, 20, 22, 21, 21, 22, 23,
+        let indices = vec![0, 2, 1, 1, 2, 3];
+        let instances = vec![
+            InstanceData { model: IDENTITY_MODEL, color: [0.0, 0.0, 0.0, 0.0] },
+            InstanceData { model: IDENTITY_MODEL, color: [0.0, 0.0, 0.0, 0.0] },
+            InstanceData { model: IDENTITY_MODEL, color: [0.0, 0.0, 0.0, 0.0] },
+            InstanceData { model: IDENTITY_MODEL, color: [0.0, 0.0, 0.0, 0.0] },
+            InstanceData { model: IDENTITY_MODEL, color: [0.0, 0.0, 0.0, 0.0] },
+            InstanceData { model: IDENTITY_MODEL, color: [0.0, 0.0, 0.0, 0.0] },
         ];
+        (verts, indices, instances)
+    }
+
+    async fn regen_mesh1(render_api: &RenderApi) -> Result<MeshInfo, RenderError> {
+        let (verts, indices) = Self::mesh1_data();
 
         let num_elements = indices.len() as i32;
-        let vertex_buffer = render_api.new_vertex_buffer(verts);
-        let index_buffer = render_api.new_index_buffer(indices);
+        let vertex_buffer = render_api.new_vertex_buffer(verts).await?;
+        let index_buffer = render_api.new_index_buffer(indices).await?;
+
+        Ok(MeshInfo {
+            vertex_buffer,
+            index_buffer,
+            num_elements,
+            instance_buffer: None,
+            num_instances: 1,
+        })
+    }
+
+    async fn regen_mesh2(render_api: &RenderApi) -> Result<MeshInfo, RenderError> {
+        let (verts, indices) = Self::mesh2_data();
 
-        std::thread::sleep(std::time::Duration::from_micros(900));
-        MeshInfo { vertex_buffer, index_buffer, num_elements }
+        let num_elements = indices.len() as i32;
+        let vertex_buffer = render_api.new_vertex_buffer(verts).await?;
+        let index_buffer = render_api.new_index_buffer(indices).await?;
+
+        Ok(MeshInfo {
+            vertex_buffer,
+            index_buffer,
+            num_elements,
+            instance_buffer: None,
+            num_instances: 1,
+        })
+    }
+
+    async fn regen_mesh3(render_api: &RenderApi) -> Result<MeshInfo, RenderError> {
+        let (verts, indices, instances) = Self::mesh3_data();
+
+        let num_elements = indices.len() as i32;
+        let num_instances = instances.len() as i32;
+        let vertex_buffer = render_api.new_vertex_buffer(verts).await?;
+        let index_buffer = render_api.new_index_buffer(indices).await?;
+        let instance_buffer = Some(render_api.new_instance_buffer(instances).await?);
+
+        Ok(MeshInfo { vertex_buffer, index_buffer, num_elements, instance_buffer, num_instances })
     }
 }
 
 pub type GfxTextureId = u32;
 pub type GfxBufferId = u32;
 
+/// Column-major 4x4 identity, the default per-instance transform.
+const IDENTITY_MODEL: [f32; 16] = [
+    1.0, 0.0, 0.0, 0.0, //
+    0.0, 1.0, 0.0, 0.0, //
+    0.0, 0.0, 1.0, 0.0, //
+    0.0, 0.0, 0.0, 1.0, //
+];
+
 #[derive(Clone, Debug)]
 pub struct MeshInfo {
     pub vertex_buffer: GfxBufferId,
     pub index_buffer: GfxBufferId,
     pub num_elements: i32,
+    /// When set, the mesh is drawn instanced: `vertex_buffer`/`index_buffer`
+    /// hold a single base quad and this stream supplies the per-instance
+    /// transform and colour.
+    pub instance_buffer: Option<GfxBufferId>,
+    /// Number of instances to draw; `1` for a plain (non-instanced) mesh.
+    pub num_instances: i32,
+}
+
+/// Per-instance attributes consumed alongside the base quad when a mesh is
+/// drawn instanced.
+#[derive(Clone, Debug)]
+#[repr(C)]
+pub struct InstanceData {
+    pub model: [f32; 16],
+    pub color: [f32; 4],
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -239,40 +363,298 @@ impl RenderApi {
         Arc::new(Self { method_req })
     }
 
-    pub fn new_vertex_buffer(&self, verts: Vec<Vertex>) -> GfxBufferId {
+    pub async fn new_vertex_buffer(&self, verts: Vec<Vertex>) -> Result<GfxBufferId, RenderError> {
         let gfx_buffer_id = BUFFER_ID.fetch_add(1, Ordering::SeqCst);
         //debug!(target: "gfx", "Req method: new_vertex_buffer(...{}, {gfx_buffer_id})", verts.len());
         assert_eq!(verts.len() % 4, 0);
 
-        let method = GraphicsMethod::NewVertexBuffer((verts, gfx_buffer_id));
-        let _ = self.method_req.send(method);
+        let (reply, reply_recvr) = async_channel::bounded(1);
+        let method = GraphicsMethod::NewVertexBuffer { verts, gfx_buffer_id, reply };
+        self.method_req.send(method).map_err(|_| RenderError::ChannelClosed)?;
 
-        gfx_buffer_id
+        reply_recvr.recv().await.map_err(|_| RenderError::ChannelClosed)?
     }
 
-    pub fn new_index_buffer(&self, indices: Vec<u16>) -> GfxBufferId {
+    pub async fn new_index_buffer(&self, indices: Vec<u16>) -> Result<GfxBufferId, RenderError> {
         let gfx_buffer_id = BUFFER_ID.fetch_add(1, Ordering::SeqCst);
         //debug!(target: "gfx", "Req method: new_index_buffer(...{}, {gfx_buffer_id})", indices.len());
         assert_eq!(indices.len() % 6, 0);
 
-        let method = GraphicsMethod::NewIndexBuffer((indices, gfx_buffer_id));
-        let _ = self.method_req.send(method);
+        let (reply, reply_recvr) = async_channel::bounded(1);
+        let method = GraphicsMethod::NewIndexBuffer { indices, gfx_buffer_id, reply };
+        self.method_req.send(method).map_err(|_| RenderError::ChannelClosed)?;
+
+        reply_recvr.recv().await.map_err(|_| RenderError::ChannelClosed)?
+    }
+
+    pub async fn new_instance_buffer(
+        &self,
+        instances: Vec<InstanceData>,
+    ) -> Result<GfxBufferId, RenderError> {
+        let gfx_buffer_id = BUFFER_ID.fetch_add(1, Ordering::SeqCst);
+        //debug!(target: "gfx", "Req method: new_instance_buffer(...{}, {gfx_buffer_id})", instances.len());
+
+        let (reply, reply_recvr) = async_channel::bounded(1);
+        let method = GraphicsMethod::NewInstanceBuffer { instances, gfx_buffer_id, reply };
+        self.method_req.send(method).map_err(|_| RenderError::ChannelClosed)?;
 
-        gfx_buffer_id
+        reply_recvr.recv().await.map_err(|_| RenderError::ChannelClosed)?
+    }
+
+    pub async fn update_vertex_buffer(
+        &self,
+        id: GfxBufferId,
+        verts: Vec<Vertex>,
+    ) -> Result<(), RenderError> {
+        //debug!(target: "gfx", "Req method: update_vertex_buffer({id}, ...{})", verts.len());
+        assert_eq!(verts.len() % 4, 0);
+        self.update_buffer(id, BufferData::Vertices(verts)).await
     }
 
-    pub fn delete_buffer(&self, buffer: GfxBufferId) {
-        //debug!(target: "gfx", "Req method: delete_buffer({buffer})");
-        let method = GraphicsMethod::DeleteBuffer(buffer);
+    pub async fn update_index_buffer(
+        &self,
+        id: GfxBufferId,
+        indices: Vec<u16>,
+    ) -> Result<(), RenderError> {
+        //debug!(target: "gfx", "Req method: update_index_buffer({id}, ...{})", indices.len());
+        assert_eq!(indices.len() % 6, 0);
+        self.update_buffer(id, BufferData::Indices(indices)).await
+    }
+
+    pub async fn update_instance_buffer(
+        &self,
+        id: GfxBufferId,
+        instances: Vec<InstanceData>,
+    ) -> Result<(), RenderError> {
+        //debug!(target: "gfx", "Req method: update_instance_buffer({id}, ...{})", instances.len());
+        self.update_buffer(id, BufferData::Instances(instances)).await
+    }
+
+    async fn update_buffer(&self, id: GfxBufferId, data: BufferData) -> Result<(), RenderError> {
+        let (reply, reply_recvr) = async_channel::bounded(1);
+        let method = GraphicsMethod::UpdateBuffer { id, data, reply };
+        self.method_req.send(method).map_err(|_| RenderError::ChannelClosed)?;
+
+        reply_recvr.recv().await.map_err(|_| RenderError::ChannelClosed)?
+    }
+
+    /// Submit a fence marker into the method stream. The returned
+    /// [`FrameFence`] resolves once `Stage::update` has processed every method
+    /// queued ahead of it, i.e. "all work submitted up to here is done".
+    pub fn submit_fence(&self) -> FrameFence {
+        let (signal, recvr) = async_channel::bounded(1);
+        let method = GraphicsMethod::Fence(signal);
         let _ = self.method_req.send(method);
+        FrameFence { recvr }
+    }
+
+    pub async fn delete_buffer(&self, id: GfxBufferId) -> Result<(), RenderError> {
+        //debug!(target: "gfx", "Req method: delete_buffer({id})");
+        let (reply, reply_recvr) = async_channel::bounded(1);
+        let method = GraphicsMethod::DeleteBuffer { id, reply };
+        self.method_req.send(method).map_err(|_| RenderError::ChannelClosed)?;
+
+        reply_recvr.recv().await.map_err(|_| RenderError::ChannelClosed)?
     }
 }
 
+/// Submission token handed back by [`RenderApi::submit_fence`]. Awaiting it
+/// blocks until the backend has drained every method queued before the fence.
+pub struct FrameFence {
+    recvr: async_channel::Receiver<()>,
+}
+
+impl FrameFence {
+    /// Wait for the backend to reach this fence.
+    pub async fn wait(self) {
+        // A dropped signal (backend gone) resolves the fence too; while the
+        // backend is alive but has not reached the fence, this stays pending.
+        let _ = self.recvr.recv().await;
+    }
+}
+
+/// Reply channel carrying the result of a buffer-creating `GraphicsMethod`.
+type ReplyId = async_channel::Sender<Result<GfxBufferId, RenderError>>;
+/// Reply channel for methods that only acknowledge success or failure.
+type ReplyUnit = async_channel::Sender<Result<(), RenderError>>;
+
+/// Failure modes surfaced back to the caller now that buffer operations are
+/// acknowledged rather than fire-and-forget.
+#[derive(Clone, Debug)]
+pub enum RenderError {
+    /// The method channel to `Stage` (or its reply channel) was dropped,
+    /// usually because the UI backend is not running.
+    ChannelClosed,
+    /// An `UpdateBuffer`/`DeleteBuffer` referenced a `GfxBufferId` the backend
+    /// does not know about.
+    UnknownBuffer(GfxBufferId),
+    /// The rendering backend rejected the operation.
+    BackendError(String),
+    /// The render graph's read/write dependencies formed a cycle and could
+    /// not be scheduled.
+    CycleDetected,
+}
+
+impl std::fmt::Display for RenderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ChannelClosed => write!(f, "render method channel closed"),
+            Self::UnknownBuffer(id) => write!(f, "unknown gfx_buffer_id {id}"),
+            Self::BackendError(msg) => write!(f, "backend error: {msg}"),
+            Self::CycleDetected => write!(f, "render graph contains a cycle"),
+        }
+    }
+}
+
+impl std::error::Error for RenderError {}
+
 #[derive(Clone, Debug)]
 pub enum GraphicsMethod {
-    NewVertexBuffer((Vec<Vertex>, GfxBufferId)),
-    NewIndexBuffer((Vec<u16>, GfxBufferId)),
-    DeleteBuffer(GfxBufferId),
+    NewVertexBuffer { verts: Vec<Vertex>, gfx_buffer_id: GfxBufferId, reply: ReplyId },
+    NewIndexBuffer { indices: Vec<u16>, gfx_buffer_id: GfxBufferId, reply: ReplyId },
+    NewInstanceBuffer { instances: Vec<InstanceData>, gfx_buffer_id: GfxBufferId, reply: ReplyId },
+    UpdateBuffer { id: GfxBufferId, data: BufferData, reply: ReplyUnit },
+    DeleteBuffer { id: GfxBufferId, reply: ReplyUnit },
+    /// A fence marker; signalled once every preceding method has been handled.
+    Fence(async_channel::Sender<()>),
+}
+
+/// Identifies a node within a [`RenderGraph`].
+pub type NodeId = usize;
+
+/// A unit of work in the render graph, declaring the buffers it reads and
+/// writes so the scheduler can order it relative to the other nodes.
+#[derive(Clone, Debug)]
+pub struct RenderNode {
+    pub label: String,
+    pub reads: Vec<GfxBufferId>,
+    pub writes: Vec<GfxBufferId>,
+}
+
+/// A declarative dependency graph over buffer/draw work for a single frame.
+///
+/// Callers register nodes stating which `GfxBufferId`s they read and write;
+/// `schedule` turns the resulting read/write hazards into a DAG and returns
+/// the nodes grouped into dependency levels. Nodes within a level are mutually
+/// independent and may run concurrently; each level must complete before the
+/// next begins.
+#[derive(Default)]
+pub struct RenderGraph {
+    nodes: Vec<RenderNode>,
+}
+
+impl RenderGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_node(
+        &mut self,
+        label: impl Into<String>,
+        reads: Vec<GfxBufferId>,
+        writes: Vec<GfxBufferId>,
+    ) -> NodeId {
+        let id = self.nodes.len();
+        self.nodes.push(RenderNode { label: label.into(), reads, writes });
+        id
+    }
+
+    /// Order the nodes into dependency levels, or return
+    /// [`RenderError::CycleDetected`] if the read/write hazards form a cycle.
+    pub fn schedule(&self) -> Result<Vec<Vec<NodeId>>, RenderError> {
+        let n = self.nodes.len();
+        let mut adj: Vec<Vec<NodeId>> = vec![Vec::new(); n];
+        let mut indeg = vec![0usize; n];
+
+        // Derive edges from resource hazards in registration order: read-after-
+        // write, write-after-read and write-after-write all force ordering.
+        let mut last_writer: HashMap<GfxBufferId, NodeId> = HashMap::new();
+        let mut readers: HashMap<GfxBufferId, Vec<NodeId>> = HashMap::new();
+        for (i, node) in self.nodes.iter().enumerate() {
+            for &r in &node.reads {
+                if let Some(&w) = last_writer.get(&r) {
+                    Self::add_edge(w, i, &mut adj, &mut indeg);
+                }
+                readers.entry(r).or_default().push(i);
+            }
+            for &w in &node.writes {
+                if let Some(rs) = readers.get(&w) {
+                    for &rd in rs {
+                        Self::add_edge(rd, i, &mut adj, &mut indeg);
+                    }
+                }
+                if let Some(&pw) = last_writer.get(&w) {
+                    Self::add_edge(pw, i, &mut adj, &mut indeg);
+                }
+                last_writer.insert(w, i);
+                readers.insert(w, Vec::new());
+            }
+        }
+
+        // Kahn's algorithm, emitting one level of ready nodes at a time.
+        let mut levels = Vec::new();
+        let mut done = vec![false; n];
+        let mut remaining = n;
+        while remaining > 0 {
+            let level: Vec<NodeId> =
+                (0..n).filter(|&i| !done[i] && indeg[i] == 0).collect();
+            if level.is_empty() {
+                return Err(RenderError::CycleDetected);
+            }
+            for &u in &level {
+                done[u] = true;
+                remaining -= 1;
+                for &v in &adj[u] {
+                    indeg[v] -= 1;
+                }
+            }
+            levels.push(level);
+        }
+
+        Ok(levels)
+    }
+
+    fn add_edge(u: NodeId, v: NodeId, adj: &mut [Vec<NodeId>], indeg: &mut [usize]) {
+        if u != v && !adj[u].contains(&v) {
+            adj[u].push(v);
+            indeg[v] += 1;
+        }
+    }
+}
+
+/// Payload for an in-place `UpdateBuffer`, overwriting the contents of an
+/// existing streaming buffer.
+#[derive(Clone, Debug)]
+pub enum BufferData {
+    Vertices(Vec<Vertex>),
+    Indices(Vec<u16>),
+    Instances(Vec<InstanceData>),
+}
+
+/// Maximum number of GPU buffers the reclaim pool will hold onto before it
+/// starts actually deleting returned buffers. Keeps the pool from growing
+/// without bound when geometry sizes keep changing.
+const POOL_HIGH_WATER_MARK: usize = 64;
+
+/// Distinguishes the two buffer flavours we pool. `BufferType` is not `Hash`,
+/// so we key the free-list on this instead.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum PoolKind {
+    Vertex,
+    Index,
+}
+
+/// Book-keeping for a live GPU buffer, enough to decide whether it can be
+/// returned to the reclaim pool and under which key.
+struct BufferEntry {
+    buffer: miniquad::BufferId,
+    kind: PoolKind,
+    /// Byte size of the allocation, used as part of the pool key so a reused
+    /// buffer is always large enough for the data overwriting it.
+    size: usize,
+    /// Only buffers created with a reusable usage may be overwritten in place
+    /// and therefore pooled.
+    reusable: bool,
 }
 
 struct Stage {
@@ -280,7 +662,13 @@ struct Stage {
     app: AppPtr,
 
     ctx: Box<dyn RenderingBackend>,
-    buffers: HashMap<GfxBufferId, miniquad::BufferId>,
+    buffers: HashMap<GfxBufferId, BufferEntry>,
+
+    /// Free-list of reclaimed buffers keyed by `(kind, byte_size)`, so a
+    /// `NewVertexBuffer`/`NewIndexBuffer` of a matching shape can reuse an
+    /// existing allocation via `buffer_update` instead of allocating afresh.
+    pool: HashMap<(PoolKind, usize), Vec<miniquad::BufferId>>,
+    pool_len: usize,
 
     method_rep: mpsc::Receiver<GraphicsMethod>,
     resize_sendr: Sender<()>,
@@ -294,7 +682,48 @@ impl Stage {
     ) -> Self {
         let ctx: Box<dyn RenderingBackend> = window::new_rendering_backend();
 
-        Stage { app, ctx, buffers: HashMap::new(), method_rep, resize_sendr }
+        Stage {
+            app,
+            ctx,
+            buffers: HashMap::new(),
+            pool: HashMap::new(),
+            pool_len: 0,
+            method_rep,
+            resize_sendr,
+        }
+    }
+
+    /// Return a buffer of the requested `kind`/`size`, overwriting a pooled
+    /// allocation in place when one is available and otherwise creating a
+    /// fresh reusable buffer. Buffers handed out here are always poolable.
+    fn acquire_buffer(
+        &mut self,
+        kind: PoolKind,
+        buffer_type: BufferType,
+        size: usize,
+        source: BufferSource,
+    ) -> (miniquad::BufferId, bool) {
+        if let Some(ids) = self.pool.get_mut(&(kind, size)) {
+            if let Some(buffer) = ids.pop() {
+                self.pool_len -= 1;
+                self.ctx.buffer_update(buffer, source);
+                return (buffer, true);
+            }
+        }
+
+        let buffer = self.ctx.new_buffer(buffer_type, BufferUsage::Stream, source);
+        (buffer, true)
+    }
+
+    /// Return a buffer to the reclaim pool if it is reusable and there is room
+    /// under the high-water-mark, otherwise delete it outright.
+    fn release_buffer(&mut self, entry: BufferEntry) {
+        if entry.reusable && self.pool_len < POOL_HIGH_WATER_MARK {
+            self.pool.entry((entry.kind, entry.size)).or_default().push(entry.buffer);
+            self.pool_len += 1;
+        } else {
+            self.ctx.delete_buffer(entry.buffer);
+        }
     }
 }
 
@@ -303,29 +732,88 @@ impl EventHandler for Stage {
         //// Process as many methods as we can
         while let Ok(method) = self.method_rep.try_recv() {
             match method {
-                GraphicsMethod::NewVertexBuffer((verts, gfx_buffer_id)) => {
-                    let buffer = self.ctx.new_buffer(
+                GraphicsMethod::NewVertexBuffer { verts, gfx_buffer_id, reply } => {
+                    let size = std::mem::size_of_val(verts.as_slice());
+                    let (buffer, reusable) = self.acquire_buffer(
+                        PoolKind::Vertex,
                         BufferType::VertexBuffer,
-                        BufferUsage::Immutable,
+                        size,
                         BufferSource::slice(&verts),
                     );
                     debug!(target: "gfx", "Invoked method: new_vertex_buffer(..., {gfx_buffer_id}) -> {buffer:?}");
-                    self.buffers.insert(gfx_buffer_id, buffer);
+                    self.buffers.insert(
+                        gfx_buffer_id,
+                        BufferEntry { buffer, kind: PoolKind::Vertex, size, reusable },
+                    );
+                    let _ = reply.try_send(Ok(gfx_buffer_id));
                 }
-                GraphicsMethod::NewIndexBuffer((indices, gfx_buffer_id)) => {
-                    let buffer = self.ctx.new_buffer(
+                GraphicsMethod::NewIndexBuffer { indices, gfx_buffer_id, reply } => {
+                    let size = std::mem::size_of_val(indices.as_slice());
+                    let (buffer, reusable) = self.acquire_buffer(
+                        PoolKind::Index,
                         BufferType::IndexBuffer,
-                        BufferUsage::Immutable,
+                        size,
                         BufferSource::slice(&indices),
                     );
                     debug!(target: "gfx", "Invoked method: new_index_buffer(..., {gfx_buffer_id}) -> {buffer:?}");
-                    self.buffers.insert(gfx_buffer_id, buffer);
+                    self.buffers.insert(
+                        gfx_buffer_id,
+                        BufferEntry { buffer, kind: PoolKind::Index, size, reusable },
+                    );
+                    let _ = reply.try_send(Ok(gfx_buffer_id));
+                }
+                GraphicsMethod::NewInstanceBuffer { instances, gfx_buffer_id, reply } => {
+                    let size = std::mem::size_of_val(instances.as_slice());
+                    let (buffer, reusable) = self.acquire_buffer(
+                        PoolKind::Vertex,
+                        BufferType::VertexBuffer,
+                        size,
+                        BufferSource::slice(&instances),
+                    );
+                    debug!(target: "gfx", "Invoked method: new_instance_buffer(..., {gfx_buffer_id}) -> {buffer:?}");
+                    self.buffers.insert(
+                        gfx_buffer_id,
+                        BufferEntry { buffer, kind: PoolKind::Vertex, size, reusable },
+                    );
+                    let _ = reply.try_send(Ok(gfx_buffer_id));
+                }
+                GraphicsMethod::UpdateBuffer { id, data, reply } => {
+                    match self.buffers.get(&id) {
+                        Some(entry) => {
+                            match &data {
+                                BufferData::Vertices(verts) => {
+                                    self.ctx.buffer_update(entry.buffer, BufferSource::slice(verts))
+                                }
+                                BufferData::Indices(indices) => self
+                                    .ctx
+                                    .buffer_update(entry.buffer, BufferSource::slice(indices)),
+                                BufferData::Instances(instances) => self
+                                    .ctx
+                                    .buffer_update(entry.buffer, BufferSource::slice(instances)),
+                            }
+                            debug!(target: "gfx", "Invoked method: update_buffer({id} = {:?})", entry.buffer);
+                            let _ = reply.try_send(Ok(()));
+                        }
+                        None => {
+                            error!(target: "gfx", "update_buffer({id}): unknown gfx_buffer_id");
+                            let _ = reply.try_send(Err(RenderError::UnknownBuffer(id)));
+                        }
+                    }
                 }
-                GraphicsMethod::DeleteBuffer(gfx_buffer_id) => {
-                    let buffer =
-                        self.buffers.remove(&gfx_buffer_id).expect("couldn't find gfx_buffer_id");
-                    debug!(target: "gfx", "Invoked method: delete_buffer({gfx_buffer_id} = {buffer:?})");
-                    self.ctx.delete_buffer(buffer);
+                GraphicsMethod::DeleteBuffer { id, reply } => match self.buffers.remove(&id) {
+                    Some(entry) => {
+                        debug!(target: "gfx", "Invoked method: delete_buffer({id} = {:?})", entry.buffer);
+                        self.release_buffer(entry);
+                        let _ = reply.try_send(Ok(()));
+                    }
+                    None => {
+                        error!(target: "gfx", "delete_buffer({id}): unknown gfx_buffer_id");
+                        let _ = reply.try_send(Err(RenderError::UnknownBuffer(id)));
+                    }
+                },
+                GraphicsMethod::Fence(signal) => {
+                    debug!(target: "gfx", "Invoked method: fence");
+                    let _ = signal.try_send(());
                 }
             };
         }